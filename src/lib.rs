@@ -1,8 +1,12 @@
 #![doc = include_str!("../README.md")]
 
+use std::sync::Arc;
+
 use bevy::{
+    ecs::system::EntityCommands,
     input::mouse::MouseWheel,
     prelude::*,
+    utils::HashMap,
 };
 
 /// A `Plugin` providing the systems and components required to make a ScrollView work.
@@ -22,21 +26,51 @@ impl Plugin for ScrollViewPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<ScrollView>()
             .register_type::<ScrollableContent>()
+            .register_type::<ScrollDirection>()
+            .register_type::<ScrollInputGate>()
+            .register_type::<ScrollBar>()
+            .register_type::<ScrollBarThumb>()
+            .register_type::<Autoscroll>()
+            .register_type::<AutoscrollStrategy>()
             .add_systems(
                 Update,
                 (
                     create_scroll_view,
+                    create_scroll_bar,
                     input_mouse_pressed_move,
                     input_touch_pressed_move,
+                    scroll_bar_thumb_drag,
                     scroll_events,
                     fling_update,
+                    autoscroll_update,
                     scroll_update,
+                    virtual_scroll_update,
+                    scroll_bar_update,
                 )
                     .chain(),
             );
     }
 }
 
+/// Which axis (or axes) a [`ScrollView`] is allowed to scroll on.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ScrollDirection {
+    #[default]
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+impl ScrollDirection {
+    fn has_vertical(self) -> bool {
+        matches!(self, ScrollDirection::Vertical | ScrollDirection::Both)
+    }
+
+    fn has_horizontal(self) -> bool {
+        matches!(self, ScrollDirection::Horizontal | ScrollDirection::Both)
+    }
+}
+
 /// Root component of scroll, it should have clipped style.
 #[derive(Component, Debug, Reflect)]
 pub struct ScrollView {
@@ -44,9 +78,28 @@ pub struct ScrollView {
     /// Could be negative number to implement invert scroll
     pub scroll_speed: f32,
     pub friction: f32,
+    /// Which axis (or axes) this view scrolls on.
+    pub direction: ScrollDirection,
     pub old_mouse_y: Option<f32>,
+    pub old_mouse_x: Option<f32>,
     pub velocity: f32,
+    pub velocity_x: f32,
     pub max_scroll: f32,
+    pub max_scroll_x: f32,
+    /// When `true`, dragging/flinging past an edge is allowed to overshoot by up to
+    /// `max_overscroll`, with a spring pulling it back to the bound once released.
+    pub enable_overscroll: bool,
+    /// Spring stiffness used to pull an overscrolled axis back to its bound.
+    pub overscroll_stiffness: f32,
+    /// Maximum distance, in logical pixels, an axis is allowed to overshoot its bound by.
+    pub max_overscroll: f32,
+    /// Which `Interaction` state activates this view's wheel input. Click/touch drag always
+    /// requires `Interaction::Pressed`, whichever gate is set.
+    pub input_gate: ScrollInputGate,
+    /// When `true` (the default), wheel input this view's clamp couldn't fully consume is
+    /// passed on to the nearest ancestor `ScrollView`. Set to `false` for a view that should
+    /// never let scroll input leak through to whatever it's nested inside.
+    pub propagate_scroll: bool,
 }
 
 impl Default for ScrollView {
@@ -54,34 +107,641 @@ impl Default for ScrollView {
         Self {
             scroll_speed: 200.0,
             friction: 4.2,
+            direction: ScrollDirection::default(),
             old_mouse_y: None,
+            old_mouse_x: None,
             velocity: 0.0,
+            velocity_x: 0.0,
             max_scroll: 0.0,
+            max_scroll_x: 0.0,
+            enable_overscroll: false,
+            overscroll_stiffness: 150.0,
+            max_overscroll: 120.0,
+            input_gate: ScrollInputGate::default(),
+            propagate_scroll: true,
         }
     }
 }
 
+/// Which `Interaction` state activates a [`ScrollView`]'s wheel input (drag input always
+/// requires a press).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ScrollInputGate {
+    /// Scroll while the pointer is hovering (or pressed down on) the view.
+    #[default]
+    Hovered,
+    /// Only scroll while the pointer is pressed down on the view.
+    Pressed,
+}
+
 /// Component containing offset value of the scroll container to the parent.
-/// It is possible to update the field `pos_y` manually to move scrollview to desired location.
+/// It is possible to update the fields `pos_x`/`pos_y` manually to move scrollview to desired location.
 #[derive(Component, Debug, Reflect, Default)]
 pub struct ScrollableContent {
-    /// Scroll container offset to the `ScrollView`.
+    /// Scroll container vertical offset to the `ScrollView`.
     pub pos_y: f32,
+    /// Scroll container horizontal offset to the `ScrollView`.
+    pub pos_x: f32,
+}
+
+/// Opt-in scrollbar for a [`ScrollView`]. Spawn as a direct child of the `ScrollView` entity
+/// (a plain `NodeBundle` carrying this component is enough); a draggable [`ScrollBarThumb`]
+/// child is created automatically and kept in sync with the sibling [`ScrollableContent`].
+#[derive(Component, Debug, Reflect)]
+pub struct ScrollBar {
+    /// Width (for a vertical bar) of the track and thumb, in logical pixels.
+    pub width: f32,
+    /// How long the bar stays fully visible after the last scroll activity, in seconds.
+    pub idle_time: f32,
+    /// How long the fade-out animation takes once `idle_time` has elapsed, in seconds.
+    pub fade_time: f32,
+    idle_elapsed: f32,
+}
+
+impl Default for ScrollBar {
+    fn default() -> Self {
+        Self {
+            width: 8.0,
+            idle_time: 1.0,
+            fade_time: 0.3,
+            idle_elapsed: 0.0,
+        }
+    }
+}
+
+/// Marker for the draggable thumb of a [`ScrollBar`], spawned automatically by
+/// [`create_scroll_bar`].
+#[derive(Component, Debug, Reflect, Default)]
+pub struct ScrollBarThumb {
+    old_mouse_y: Option<f32>,
+}
+
+/// Virtualizes a very long list: only UI entities for the currently visible window (plus a
+/// small `overscan`) are kept alive, so an arbitrarily large `item_count` costs a constant
+/// number of entities. Insert alongside [`ScrollableContent`] on the scrolled entity itself;
+/// [`virtual_scroll_update`] grows that entity to the full virtual height (so `max_scroll`
+/// and the [`ScrollBar`] reflect the whole list rather than just the spawned children) and
+/// absolutely-positions each spawned item at `index * item_height`.
+///
+/// Use [`ScrollView::scroll_to_index`] rather than [`ScrollView::scroll_to_entity`] to
+/// programmatically scroll within a virtualized list: an item outside the current window
+/// doesn't have a live entity to target, and one can be silently recycled to a different
+/// index between frames.
+#[derive(Component)]
+pub struct VirtualScrollContent {
+    /// Total number of items in the list, spawned or not.
+    pub item_count: usize,
+    /// Fixed height, in logical pixels, of each item.
+    pub item_height: f32,
+    /// Extra items kept alive above/below the visible window, to absorb fast scrolling.
+    pub overscan: usize,
+    /// Builds (or rebuilds, when an entity is recycled) the contents of item `index`.
+    pub build_item: Arc<dyn Fn(usize, &mut EntityCommands) + Send + Sync>,
+    spawned: HashMap<usize, Entity>,
+    free: Vec<Entity>,
+}
+
+impl VirtualScrollContent {
+    pub fn new(
+        item_count: usize,
+        item_height: f32,
+        build_item: impl Fn(usize, &mut EntityCommands) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            item_count,
+            item_height,
+            overscan: 4,
+            build_item: Arc::new(build_item),
+            spawned: HashMap::default(),
+            free: Vec::new(),
+        }
+    }
+}
+
+impl ScrollView {
+    /// Whether this view's wheel input should be processed given its current
+    /// `Interaction` and `input_gate`.
+    fn is_active(&self, interaction: Interaction) -> bool {
+        match self.input_gate {
+            ScrollInputGate::Hovered => matches!(interaction, Interaction::Hovered | Interaction::Pressed),
+            ScrollInputGate::Pressed => interaction == Interaction::Pressed,
+        }
+    }
+
+    /// Smoothly scroll to an explicit vertical offset, easing in from the current position.
+    pub fn scroll_to(pos_y: f32) -> Autoscroll {
+        Autoscroll {
+            target: pos_y,
+            target_entity: None,
+            target_index: None,
+            strategy: AutoscrollStrategy::Instant,
+            rate: 12.0,
+        }
+    }
+
+    /// Smoothly scroll so that `entity` (a descendant of the `ScrollableContent`) is brought
+    /// into view, following `strategy`.
+    ///
+    /// Note: this does not compose with [`VirtualScrollContent`] — an entity that isn't
+    /// currently spawned (i.e. outside the visible window, the common case on a long
+    /// virtualized list) can't be found and the `Autoscroll` is dropped as a no-op. Use
+    /// [`Self::scroll_to_index`] instead when scrolling a virtualized list.
+    pub fn scroll_to_entity(entity: Entity, strategy: AutoscrollStrategy) -> Autoscroll {
+        Autoscroll {
+            target: 0.0,
+            target_entity: Some(entity),
+            target_index: None,
+            strategy,
+            rate: 12.0,
+        }
+    }
+
+    /// Smoothly scroll so that item `index` of a [`VirtualScrollContent`] is brought into
+    /// view, following `strategy`. Unlike [`Self::scroll_to_entity`], this works regardless
+    /// of whether `index` is currently spawned, since the target offset is derived from
+    /// `index * item_height` rather than an entity lookup.
+    pub fn scroll_to_index(index: usize, strategy: AutoscrollStrategy) -> Autoscroll {
+        Autoscroll {
+            target: 0.0,
+            target_entity: None,
+            target_index: Some(index),
+            strategy,
+            rate: 12.0,
+        }
+    }
+}
+
+/// Drives a [`ScrollView`] smoothly toward a target offset, entity, or virtual index. Insert
+/// via [`ScrollView::scroll_to`], [`ScrollView::scroll_to_entity`], or
+/// [`ScrollView::scroll_to_index`]; removed automatically by [`autoscroll_update`] once the
+/// target is reached.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+pub struct Autoscroll {
+    /// Desired vertical offset. Recomputed every frame when `target_entity` or `target_index`
+    /// is set.
+    pub target: f32,
+    /// When set, `target` is re-derived each frame from this entity's current bounds.
+    pub target_entity: Option<Entity>,
+    /// When set, `target` is re-derived each frame from this virtual list index (requires a
+    /// [`VirtualScrollContent`] on the view's [`ScrollableContent`] child); takes precedence
+    /// over `target_entity`.
+    pub target_index: Option<usize>,
+    pub strategy: AutoscrollStrategy,
+    /// Approach rate for the exponential ease; higher settles faster.
+    pub rate: f32,
+}
+
+/// How an [`Autoscroll`]'s `target` is computed from `target_entity`, once per frame.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum AutoscrollStrategy {
+    /// Scroll so the entity's top edge aligns with the view's top edge.
+    Instant,
+    /// Move the minimal amount so the entity's bounding box is fully visible.
+    Fit,
+    /// Center the entity within the view.
+    Center,
+}
+
+fn autoscroll_update(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q_view: Query<(Entity, &Children, &Node, &mut Autoscroll)>,
+    mut content_q: Query<(&mut ScrollableContent, &Node, &GlobalTransform, Option<&VirtualScrollContent>)>,
+    target_q: Query<(&Node, &GlobalTransform)>,
+) {
+    const EPSILON: f32 = 0.5;
+
+    for (view_entity, children, view_node, mut autoscroll) in q_view.iter_mut() {
+        let Some(content_entity) = children.iter().find(|&&c| content_q.get(c).is_ok()).copied() else {
+            commands.entity(view_entity).remove::<Autoscroll>();
+            continue;
+        };
+        let Ok((mut content, content_node, content_gt, virt)) = content_q.get_mut(content_entity) else {
+            continue;
+        };
+        let container_size = view_node.size().y;
+
+        // (target_top, target_height), independent of the current scroll position, however
+        // the target was specified.
+        let target = if let Some(index) = autoscroll.target_index {
+            let Some(virt) = virt else {
+                // no virtualized content to resolve the index against; stop autoscrolling.
+                commands.entity(view_entity).remove::<Autoscroll>();
+                continue;
+            };
+            Some((index as f32 * virt.item_height, virt.item_height))
+        } else if let Some(target_entity) = autoscroll.target_entity {
+            let Ok((target_node, target_gt)) = target_q.get(target_entity) else {
+                // the target entity no longer exists; stop autoscrolling.
+                commands.entity(view_entity).remove::<Autoscroll>();
+                continue;
+            };
+            // target's top edge relative to the content's top edge, independent of the current
+            // scroll position: both translations already include `content.pos_y`, so it cancels
+            // out. UI translations are node centres, so each is shifted up by half its height.
+            let target_top = target_gt.translation().y - target_node.size().y / 2.0;
+            let content_top = content_gt.translation().y - content_node.size().y / 2.0;
+            Some((target_top - content_top, target_node.size().y))
+        } else {
+            None
+        };
+
+        if let Some((target_top, target_height)) = target {
+            autoscroll.target = resolve_autoscroll_target(
+                autoscroll.strategy,
+                target_top,
+                target_height,
+                container_size,
+                content.pos_y,
+            );
+        }
+
+        let max_scroll = -(content_node.size().y - container_size).max(0.0);
+        autoscroll.target = autoscroll.target.clamp(max_scroll, 0.0);
+
+        let delta = autoscroll.target - content.pos_y;
+        if delta.abs() <= EPSILON {
+            content.pos_y = autoscroll.target;
+            commands.entity(view_entity).remove::<Autoscroll>();
+            continue;
+        }
+        content.pos_y += delta * (1.0 - (-autoscroll.rate * time.delta_seconds()).exp());
+    }
+}
+
+/// Computes an [`Autoscroll`]'s unclamped target `pos_y` for a target sitting `target_top`
+/// below the top of the content (scroll-independent), given the view's `container_size` and
+/// the content's `current_pos_y`.
+fn resolve_autoscroll_target(
+    strategy: AutoscrollStrategy,
+    target_top: f32,
+    target_height: f32,
+    container_size: f32,
+    current_pos_y: f32,
+) -> f32 {
+    let target_bottom = target_top + target_height;
+    match strategy {
+        AutoscrollStrategy::Instant => -target_top,
+        AutoscrollStrategy::Fit => {
+            if target_top < -current_pos_y {
+                -target_top
+            } else if target_bottom > -current_pos_y + container_size {
+                -(target_bottom - container_size)
+            } else {
+                current_pos_y
+            }
+        }
+        AutoscrollStrategy::Center => -(target_top + target_height / 2.0 - container_size / 2.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A 400px-tall target list with a 100px container viewing the top (pos_y = 0.0), and a
+    // 20px-tall item sitting 250px into the content.
+    const CONTAINER_SIZE: f32 = 100.0;
+    const TARGET_TOP: f32 = 250.0;
+    const TARGET_HEIGHT: f32 = 20.0;
+
+    #[test]
+    fn instant_aligns_target_top_with_container_top() {
+        let target = resolve_autoscroll_target(AutoscrollStrategy::Instant, TARGET_TOP, TARGET_HEIGHT, CONTAINER_SIZE, 0.0);
+        assert_eq!(target, -TARGET_TOP);
+    }
+
+    #[test]
+    fn center_centers_target_within_container() {
+        let target = resolve_autoscroll_target(AutoscrollStrategy::Center, TARGET_TOP, TARGET_HEIGHT, CONTAINER_SIZE, 0.0);
+        assert_eq!(target, -(TARGET_TOP + TARGET_HEIGHT / 2.0 - CONTAINER_SIZE / 2.0));
+    }
+
+    #[test]
+    fn fit_scrolls_down_when_target_is_below_the_visible_window() {
+        // visible window is [0, 100) at pos_y = 0.0; the target at [250, 270) is fully below it.
+        let target = resolve_autoscroll_target(AutoscrollStrategy::Fit, TARGET_TOP, TARGET_HEIGHT, CONTAINER_SIZE, 0.0);
+        assert_eq!(target, -(TARGET_TOP + TARGET_HEIGHT - CONTAINER_SIZE));
+    }
+
+    #[test]
+    fn fit_scrolls_up_when_target_is_above_the_visible_window() {
+        // visible window is [300, 400) at pos_y = -300.0; the target at [250, 270) is above it.
+        let target = resolve_autoscroll_target(AutoscrollStrategy::Fit, TARGET_TOP, TARGET_HEIGHT, CONTAINER_SIZE, -300.0);
+        assert_eq!(target, -TARGET_TOP);
+    }
+
+    #[test]
+    fn fit_is_a_no_op_when_target_is_already_visible() {
+        // visible window is [240, 340) at pos_y = -240.0; the target at [250, 270) is inside it.
+        let target = resolve_autoscroll_target(AutoscrollStrategy::Fit, TARGET_TOP, TARGET_HEIGHT, CONTAINER_SIZE, -240.0);
+        assert_eq!(target, -240.0);
+    }
+
+    #[test]
+    fn overscroll_resistance_is_unity_in_bounds_or_disabled() {
+        assert_eq!(overscroll_resistance(-50.0, -100.0, 200.0, true), 1.0);
+        assert_eq!(overscroll_resistance(50.0, -100.0, 200.0, false), 1.0);
+        assert_eq!(overscroll_resistance(50.0, -100.0, 0.0, true), 1.0);
+    }
+
+    #[test]
+    fn overscroll_resistance_grows_with_overscroll_distance() {
+        // 50px past the top bound (0.0) on a 200px extent.
+        assert_eq!(overscroll_resistance(50.0, -100.0, 200.0, true), 1.0 + 50.0 / 200.0);
+        // 50px past the bottom bound (-100.0) on a 200px extent.
+        assert_eq!(overscroll_resistance(-150.0, -100.0, 200.0, true), 1.0 + 50.0 / 200.0);
+    }
+
+    #[test]
+    fn apply_scroll_axis_flings_toward_zero_velocity_while_in_bounds() {
+        let (pos, velocity) = apply_scroll_axis(-500.0, 60.0, -1000.0, 0.0, 4.0, false, None, 1.0 / 60.0);
+        // moving with positive velocity inside the bounds: pos advances toward `max`, velocity decays.
+        assert!(pos > -500.0 && pos < 0.0);
+        assert!(velocity > 0.0 && velocity < 60.0);
+    }
+
+    #[test]
+    fn apply_scroll_axis_hard_clamps_out_of_bounds_without_overscroll() {
+        // friction-only fling has no spring: out of bounds is hard-clamped, not eased back.
+        let (pos, velocity) = apply_scroll_axis(10.0, 0.0, -50.0, 0.0, 4.0, false, None, 1.0 / 60.0);
+        assert_eq!(pos, 0.0);
+        assert_eq!(velocity, 0.0);
+    }
+
+    #[test]
+    fn apply_scroll_axis_springs_an_overscrolled_released_axis_back_toward_the_bound() {
+        let cfg = OverscrollConfig { stiffness: 150.0, max_overscroll: 120.0 };
+        let (pos, _) = apply_scroll_axis(20.0, 0.0, -50.0, 0.0, 4.0, false, Some(cfg), 1.0 / 60.0);
+        // released 20px past the `max` bound (0.0): the spring pulls it back toward 0, not further out.
+        assert!(pos < 20.0 && pos >= 0.0);
+    }
+
+    #[test]
+    fn apply_scroll_axis_extends_the_clamp_by_max_overscroll_while_pressed() {
+        let cfg = OverscrollConfig { stiffness: 150.0, max_overscroll: 120.0 };
+        // while pressed, a position past `max` is left alone as long as it's within max_overscroll.
+        let (pos, _) = apply_scroll_axis(50.0, 0.0, -50.0, 0.0, 4.0, true, Some(cfg), 1.0 / 60.0);
+        assert_eq!(pos, 50.0);
+    }
+
+    #[test]
+    fn visible_range_basic_window_at_top() {
+        assert_eq!(visible_range(0.0, 20.0, 100.0, 2, 1000), (0, 8));
+    }
+
+    #[test]
+    fn visible_range_shifts_with_scroll_offset() {
+        // scrolled 200px down (10 items), the window follows.
+        assert_eq!(visible_range(-200.0, 20.0, 100.0, 2, 1000), (8, 18));
+    }
+
+    #[test]
+    fn visible_range_start_is_clamped_at_zero() {
+        // first_visible = 1, overscan = 5 would underflow below index 0.
+        assert_eq!(visible_range(-30.0, 20.0, 100.0, 5, 1000), (0, 12));
+    }
+
+    #[test]
+    fn visible_range_end_is_clamped_by_item_count() {
+        assert_eq!(visible_range(-160.0, 20.0, 100.0, 2, 12), (6, 12));
+    }
+
+    #[test]
+    fn consume_scroll_delta_fully_consumes_when_in_bounds() {
+        let (new_pos, consumed) = consume_scroll_delta(-50.0, 10.0, -100.0, 0.0);
+        assert_eq!(new_pos, -40.0);
+        assert_eq!(consumed, 10.0);
+    }
+
+    #[test]
+    fn consume_scroll_delta_partially_consumes_at_a_bound() {
+        // only 5 of the 10px delta fits before hitting the `max` bound.
+        let (new_pos, consumed) = consume_scroll_delta(-5.0, 10.0, -100.0, 0.0);
+        assert_eq!(new_pos, 0.0);
+        assert_eq!(consumed, 5.0);
+    }
+
+    #[test]
+    fn consume_scroll_delta_rejects_entirely_when_already_at_the_bound() {
+        let (new_pos, consumed) = consume_scroll_delta(0.0, 10.0, -100.0, 0.0);
+        assert_eq!(new_pos, 0.0);
+        assert_eq!(consumed, 0.0);
+    }
+
+    #[test]
+    fn unconsumed_delta_propagates_to_the_next_view_in_the_chain() {
+        // inner view only has 5px of room before its `max` bound...
+        let dy = 10.0;
+        let (inner_pos, inner_consumed) = consume_scroll_delta(-5.0, dy, -100.0, 0.0);
+        assert_eq!(inner_pos, 0.0);
+        let remaining = dy * (1.0 - (inner_consumed / dy).clamp(0.0, 1.0));
+        assert_eq!(remaining, 5.0);
+
+        // ...so the outer (ancestor) view should only see the leftover 5px.
+        let (outer_pos, outer_consumed) = consume_scroll_delta(-50.0, remaining, -200.0, 0.0);
+        assert_eq!(outer_pos, -45.0);
+        assert_eq!(outer_consumed, 5.0);
+    }
 }
 
 pub fn create_scroll_view(
     mut commands: Commands,
-    mut q: Query<(Entity, &mut Style), Added<ScrollView>>,
+    mut q: Query<(Entity, &mut Style, &ScrollView), Added<ScrollView>>,
 ) {
-    for (e, mut style) in q.iter_mut() {
+    for (e, mut style, view) in q.iter_mut() {
         style.overflow = Overflow::clip();
         style.align_items = AlignItems::Start;
         style.align_self = AlignSelf::Stretch;
-        style.flex_direction = FlexDirection::Row;
+        style.flex_direction = match view.direction {
+            ScrollDirection::Horizontal => FlexDirection::Column,
+            ScrollDirection::Vertical | ScrollDirection::Both => FlexDirection::Row,
+        };
         commands.entity(e).insert(Interaction::None);
     }
 }
 
+fn create_scroll_bar(
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut Style, &mut ScrollBar), Added<ScrollBar>>,
+) {
+    for (e, mut style, mut bar) in q.iter_mut() {
+        bar.idle_elapsed = 0.0;
+        style.position_type = PositionType::Absolute;
+        style.right = Val::Px(0.0);
+        style.top = Val::Px(0.0);
+        style.bottom = Val::Px(0.0);
+        style.width = Val::Px(bar.width);
+        commands
+            .entity(e)
+            .insert(BackgroundColor(Color::rgba(1.0, 1.0, 1.0, 0.0)))
+            .with_children(|p| {
+                p.spawn((
+                    NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Relative,
+                            width: Val::Percent(100.0),
+                            ..default()
+                        },
+                        background_color: Color::rgba(1.0, 1.0, 1.0, 0.0).into(),
+                        ..default()
+                    },
+                    ScrollBarThumb::default(),
+                    Interaction::None,
+                ));
+            });
+    }
+}
+
+fn scroll_bar_thumb_drag(
+    mut thumb_q: Query<(&Parent, &Interaction, &mut ScrollBarThumb)>,
+    bar_q: Query<&Parent, With<ScrollBar>>,
+    view_children_q: Query<&Children, With<ScrollView>>,
+    mut content_q: Query<(&mut ScrollableContent, &Node)>,
+    node_q: Query<&Node>,
+    windows: Query<&Window>,
+) {
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let Some(pos) = window.cursor_position() else {
+        return;
+    };
+
+    for (thumb_parent, &interaction, mut thumb) in thumb_q.iter_mut() {
+        if interaction != Interaction::Pressed {
+            thumb.old_mouse_y = None;
+            continue;
+        }
+        let delta = if let Some(old_y) = thumb.old_mouse_y {
+            pos.y - old_y
+        } else {
+            0.0
+        };
+        thumb.old_mouse_y = Some(pos.y);
+
+        let Ok(bar_parent) = bar_q.get(thumb_parent.get()) else {
+            continue;
+        };
+        let view_entity = bar_parent.get();
+        let Ok(view_children) = view_children_q.get(view_entity) else {
+            continue;
+        };
+        let Ok(view_node) = node_q.get(view_entity) else {
+            continue;
+        };
+        let Some(content_entity) = view_children.iter().find(|&&c| content_q.get(c).is_ok()) else {
+            continue;
+        };
+        let Ok((mut content, content_node)) = content_q.get_mut(*content_entity) else {
+            continue;
+        };
+
+        let container_size = view_node.size().y;
+        let content_size = content_node.size().y.max(container_size);
+        let visible_ratio = (container_size / content_size).clamp(0.0, 1.0);
+        if visible_ratio >= 1.0 {
+            continue;
+        }
+        let max_scroll = -(content_size - container_size).max(0.0);
+        // inverse of the visible ratio: moving the thumb by `delta` px scrolls the (larger)
+        // content by `delta / visible_ratio` px the opposite way (thumb down, content up).
+        content.pos_y = (content.pos_y - delta / visible_ratio).clamp(max_scroll, 0.0);
+    }
+}
+
+/// How much a drag delta past an edge should be dampened by, given the current overscroll
+/// distance and the container extent along that axis. `1.0` means no resistance.
+fn overscroll_resistance(pos: f32, min: f32, extent: f32, enabled: bool) -> f32 {
+    if !enabled || extent <= 0.0 {
+        return 1.0;
+    }
+    let over = if pos > 0.0 {
+        pos
+    } else if pos < min {
+        pos - min
+    } else {
+        0.0
+    };
+    1.0 + over.abs() / extent
+}
+
+/// Computes the `[start, end)` item-index window that should have live entities, given the
+/// current scroll offset, a fixed `item_height`, the visible `container_height`, an `overscan`
+/// margin kept alive on either side, and the list's total `item_count`.
+fn visible_range(pos_y: f32, item_height: f32, container_height: f32, overscan: usize, item_count: usize) -> (usize, usize) {
+    let first_visible = ((-pos_y) / item_height).floor().max(0.0) as usize;
+    let visible_count = (container_height / item_height).ceil() as usize + 1;
+    let start = first_visible.saturating_sub(overscan);
+    let end = (first_visible + visible_count + overscan).min(item_count);
+    (start, end)
+}
+
+fn virtual_scroll_update(
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut VirtualScrollContent, &ScrollableContent, &mut Style)>,
+    parent_q: Query<&Parent>,
+    view_q: Query<&Node, With<ScrollView>>,
+) {
+    for (content_entity, mut virt, scroll, mut style) in q.iter_mut() {
+        style.height = Val::Px(virt.item_count as f32 * virt.item_height);
+
+        let Ok(parent) = parent_q.get(content_entity) else {
+            continue;
+        };
+        let Ok(view_node) = view_q.get(parent.get()) else {
+            continue;
+        };
+        let container_height = view_node.size().y;
+
+        let (start, end) = visible_range(scroll.pos_y, virt.item_height, container_height, virt.overscan, virt.item_count);
+
+        let stale: Vec<usize> = virt.spawned.keys().copied().filter(|index| *index < start || *index >= end).collect();
+        for index in stale {
+            let entity = virt.spawned.remove(&index).unwrap();
+            if let Some(mut entity_commands) = commands.get_entity(entity) {
+                entity_commands.insert(Style {
+                    display: Display::None,
+                    ..default()
+                });
+            }
+            virt.free.push(entity);
+        }
+
+        for index in start..end {
+            if virt.spawned.contains_key(&index) {
+                continue;
+            }
+            let entity = match virt.free.pop() {
+                Some(entity) => {
+                    // clear whatever `build_item` spawned for this entity's previous index,
+                    // otherwise recycling it into a new index leaks children forever.
+                    if let Some(mut entity_commands) = commands.get_entity(entity) {
+                        entity_commands.despawn_descendants();
+                    }
+                    entity
+                }
+                None => {
+                    let entity = commands.spawn_empty().id();
+                    commands.entity(content_entity).add_child(entity);
+                    entity
+                }
+            };
+            if let Some(mut entity_commands) = commands.get_entity(entity) {
+                entity_commands.insert(Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(index as f32 * virt.item_height),
+                    height: Val::Px(virt.item_height),
+                    width: Val::Percent(100.0),
+                    ..default()
+                });
+                (virt.build_item)(index, &mut entity_commands);
+            }
+            virt.spawned.insert(index, entity);
+        }
+    }
+}
+
 fn input_mouse_pressed_move(
     mut q: Query<(&Children, &Interaction, &Node, &mut ScrollView)>,
     mut content_q: Query<(&mut ScrollableContent, &Node)>,
@@ -93,24 +753,40 @@ fn input_mouse_pressed_move(
     };
     if let Some(pos) = window.cursor_position() {
         for (children, &interaction, node, mut view) in q.iter_mut() {
+            // click-drag always requires an actual press, regardless of `input_gate` — that
+            // flag only gates wheel input in `scroll_events`.
             if interaction != Interaction::Pressed {
                 view.old_mouse_y = None;
+                view.old_mouse_x = None;
                 continue;
             }
-            let delta = if let Some(old_y) = view.old_mouse_y {
+            let delta_y = if let Some(old_y) = view.old_mouse_y {
                 pos.y - old_y
             } else {
                 0.0
             };
+            let delta_x = if let Some(old_x) = view.old_mouse_x {
+                pos.x - old_x
+            } else {
+                0.0
+            };
             view.old_mouse_y = Some(pos.y);
-            view.velocity = (view.velocity + delta / time.delta_seconds()) / 2.0;
+            view.old_mouse_x = Some(pos.x);
             view.max_scroll = 0.0;
-            // iterate children and find the bottom of the last one
+            view.max_scroll_x = 0.0;
+            let mut resistance_y = 1.0;
+            let mut resistance_x = 1.0;
+            // iterate children and find the bottom/right of the last one
             for &child in children.iter() {
                 if let Ok(item) = content_q.get_mut(child) {
                     view.max_scroll = view.max_scroll.min(-(item.1.size().y - node.size().y).max(0.0));
+                    view.max_scroll_x = view.max_scroll_x.min(-(item.1.size().x - node.size().x).max(0.0));
+                    resistance_y = overscroll_resistance(item.0.pos_y, view.max_scroll, node.size().y, view.enable_overscroll);
+                    resistance_x = overscroll_resistance(item.0.pos_x, view.max_scroll_x, node.size().x, view.enable_overscroll);
                 }
             }
+            view.velocity = (view.velocity + delta_y / resistance_y / time.delta_seconds()) / 2.0;
+            view.velocity_x = (view.velocity_x + delta_x / resistance_x / time.delta_seconds()) / 2.0;
         }
     }
 }
@@ -127,47 +803,134 @@ fn input_touch_pressed_move(
         };
 
         for (children, &interaction, node, mut view) in q.iter_mut() {
+            // click-drag always requires an actual press, regardless of `input_gate` — that
+            // flag only gates wheel input in `scroll_events`.
             if interaction != Interaction::Pressed {
                 continue;
             }
-            view.velocity = (view.velocity + touch.delta().y / time.delta_seconds()) / 2.0;
             view.max_scroll = 0.0;
+            view.max_scroll_x = 0.0;
+            let mut resistance_y = 1.0;
+            let mut resistance_x = 1.0;
             for &child in children.iter() {
                 if let Ok(item) = content_q.get_mut(child) {
                     view.max_scroll = view.max_scroll.min(-(item.1.size().y - node.size().y).max(0.0));
+                    view.max_scroll_x = view.max_scroll_x.min(-(item.1.size().x - node.size().x).max(0.0));
+                    resistance_y = overscroll_resistance(item.0.pos_y, view.max_scroll, node.size().y, view.enable_overscroll);
+                    resistance_x = overscroll_resistance(item.0.pos_x, view.max_scroll_x, node.size().x, view.enable_overscroll);
                 }
             }
+            view.velocity = (view.velocity + touch.delta().y / resistance_y / time.delta_seconds()) / 2.0;
+            view.velocity_x = (view.velocity_x + touch.delta().x / resistance_x / time.delta_seconds()) / 2.0;
+        }
+    }
+}
+
+/// Walks up from `from` and returns the nearest ancestor that is itself a `ScrollView`,
+/// skipping any intermediate non-`ScrollView` nodes.
+fn find_parent_scroll_view(
+    from: Entity,
+    parent_q: &Query<&Parent>,
+    scroll_views: &Query<Entity, With<ScrollView>>,
+) -> Option<Entity> {
+    let mut current = from;
+    loop {
+        let parent = parent_q.get(current).ok()?.get();
+        if scroll_views.contains(parent) {
+            return Some(parent);
         }
+        current = parent;
     }
 }
 
+/// Applies `delta` to `pos`, clamped to `[min, max]`, and reports how much of `delta` was
+/// actually consumed — the difference is what a caller should propagate to the next ancestor.
+fn consume_scroll_delta(pos: f32, delta: f32, min: f32, max: f32) -> (f32, f32) {
+    let new_pos = (pos + delta).clamp(min, max);
+    (new_pos, new_pos - pos)
+}
+
 fn scroll_events(
     mut scroll_evr: EventReader<MouseWheel>,
-    mut q: Query<(&Children, &Interaction, &ScrollView, &Node), With<ScrollView>>,
     time: Res<Time>,
+    q_view: Query<(&ScrollView, &Interaction, &Children, &Node)>,
+    parent_q: Query<&Parent>,
+    scroll_views: Query<Entity, With<ScrollView>>,
     mut content_q: Query<(&mut ScrollableContent, &Node)>,
 ) {
     use bevy::input::mouse::MouseScrollUnit;
+
+    if scroll_evr.is_empty() {
+        return;
+    }
+
+    // The innermost active view is the one with the most ScrollView ancestors; it gets first
+    // crack at the wheel delta, with only the part its clamp rejects propagating outward.
+    let innermost = scroll_views
+        .iter()
+        .filter(|&e| q_view.get(e).is_ok_and(|(view, &interaction, _, _)| view.is_active(interaction)))
+        .max_by_key(|&e| {
+            let mut depth = 0;
+            let mut current = e;
+            while let Some(parent) = find_parent_scroll_view(current, &parent_q, &scroll_views) {
+                depth += 1;
+                current = parent;
+            }
+            depth
+        });
+
+    let Some(innermost) = innermost else {
+        return;
+    };
+
+    let mut chain = vec![innermost];
+    while q_view.get(*chain.last().unwrap()).is_ok_and(|(view, ..)| view.propagate_scroll) {
+        match find_parent_scroll_view(*chain.last().unwrap(), &parent_q, &scroll_views) {
+            Some(next) => chain.push(next),
+            None => break,
+        }
+    }
+
     for ev in scroll_evr.read() {
-        for (children, &interaction, scroll_view, node) in q.iter_mut() {
-            let y = match ev.unit {
-                MouseScrollUnit::Line => {
-                    ev.y * time.delta().as_secs_f32() * scroll_view.scroll_speed
-                }
-                MouseScrollUnit::Pixel => ev.y,
-            };
-            if interaction != Interaction::Hovered {
-                continue;
+        let (mut remaining_x, mut remaining_y) = (ev.x, ev.y);
+
+        for &view_entity in &chain {
+            if remaining_x == 0.0 && remaining_y == 0.0 {
+                break;
             }
+            let Ok((scroll_view, _, children, node)) = q_view.get(view_entity) else {
+                continue;
+            };
             let container_height = node.size().y;
+            let container_width = node.size().x;
+            let overscroll = if scroll_view.enable_overscroll { scroll_view.max_overscroll } else { 0.0 };
 
             for &child in children.iter() {
-                if let Ok(item) = content_q.get_mut(child) {
-                    let y = y * time.delta().as_secs_f32() * scroll_view.scroll_speed;
-                    let mut scroll = item.0;
-                    let max_scroll = (item.1.size().y - container_height).max(0.0);
-                    scroll.pos_y += y;
-                    scroll.pos_y = scroll.pos_y.clamp(-max_scroll, 0.);
+                let Ok((mut scroll, content_node)) = content_q.get_mut(child) else {
+                    continue;
+                };
+
+                if scroll_view.direction.has_vertical() && remaining_y != 0.0 {
+                    let dy = match ev.unit {
+                        MouseScrollUnit::Line => remaining_y * time.delta().as_secs_f32() * scroll_view.scroll_speed,
+                        MouseScrollUnit::Pixel => remaining_y,
+                    };
+                    let max_scroll = (content_node.size().y - container_height).max(0.0);
+                    let (new_pos, consumed) = consume_scroll_delta(scroll.pos_y, dy, -max_scroll - overscroll, overscroll);
+                    scroll.pos_y = new_pos;
+                    // whatever this view's clamp rejected carries on to the next ancestor.
+                    remaining_y = if dy != 0.0 { remaining_y * (1.0 - (consumed / dy).clamp(0.0, 1.0)) } else { 0.0 };
+                }
+
+                if scroll_view.direction.has_horizontal() && remaining_x != 0.0 {
+                    let dx = match ev.unit {
+                        MouseScrollUnit::Line => remaining_x * time.delta().as_secs_f32() * scroll_view.scroll_speed,
+                        MouseScrollUnit::Pixel => remaining_x,
+                    };
+                    let max_scroll_x = (content_node.size().x - container_width).max(0.0);
+                    let (new_pos, consumed) = consume_scroll_delta(scroll.pos_x, dx, -max_scroll_x - overscroll, overscroll);
+                    scroll.pos_x = new_pos;
+                    remaining_x = if dx != 0.0 { remaining_x * (1.0 - (consumed / dx).clamp(0.0, 1.0)) } else { 0.0 };
                 }
             }
         }
@@ -177,33 +940,168 @@ fn scroll_events(
 fn scroll_update(mut q: Query<(&ScrollableContent, &mut Style), Changed<ScrollableContent>>) {
     for (scroll, mut style) in q.iter_mut() {
         style.top = Val::Px(scroll.pos_y);
+        style.left = Val::Px(scroll.pos_x);
+    }
+}
+
+fn scroll_bar_update(
+    time: Res<Time>,
+    mut bar_q: Query<(&mut ScrollBar, &Parent, &Children, &mut BackgroundColor)>,
+    view_children_q: Query<&Children, With<ScrollView>>,
+    content_q: Query<(Ref<ScrollableContent>, &Node)>,
+    node_q: Query<&Node>,
+    mut thumb_q: Query<(&mut Style, &mut BackgroundColor), With<ScrollBarThumb>>,
+) {
+    for (mut bar, parent, children, mut bar_color) in bar_q.iter_mut() {
+        let Ok(view_children) = view_children_q.get(parent.get()) else {
+            continue;
+        };
+        let Some((content, content_node)) = view_children.iter().find_map(|&c| content_q.get(c).ok()) else {
+            continue;
+        };
+        let Ok(view_node) = node_q.get(parent.get()) else {
+            continue;
+        };
+        let Some(&thumb_entity) = children.iter().find(|&&c| thumb_q.get(c).is_ok()) else {
+            continue;
+        };
+
+        if content.is_changed() {
+            bar.idle_elapsed = 0.0;
+        } else {
+            bar.idle_elapsed += time.delta_seconds();
+        }
+        let fade = ((bar.idle_elapsed - bar.idle_time) / bar.fade_time.max(f32::EPSILON)).clamp(0.0, 1.0);
+        let alpha = 1.0 - fade;
+
+        let container_size = view_node.size().y;
+        let content_size = content_node.size().y.max(container_size);
+        let visible_ratio = (container_size / content_size).clamp(0.0, 1.0);
+        let max_scroll = -(content_size - container_size).max(0.0);
+        let scroll_ratio = if max_scroll != 0.0 {
+            (content.pos_y / max_scroll).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        if let Ok((mut thumb_style, mut thumb_color)) = thumb_q.get_mut(thumb_entity) {
+            thumb_style.height = Val::Percent(visible_ratio * 100.0);
+            thumb_style.top = Val::Percent(scroll_ratio * (1.0 - visible_ratio) * 100.0);
+            thumb_color.0 = Color::rgba(1.0, 1.0, 1.0, alpha * 0.5);
+        }
+        bar_color.0 = Color::rgba(1.0, 1.0, 1.0, alpha * 0.15);
     }
 }
 
 fn fling_update(
-    mut q_view: Query<(&mut ScrollView, &Children)>,
+    mut q_view: Query<(&mut ScrollView, &Children, &Interaction)>,
     mut q_scroll: Query<&mut ScrollableContent>,
     time: Res<Time>,
 ) {
-    for (mut view, children) in q_view.iter_mut() {
+    let dt = time.delta_seconds();
+    for (mut view, children, &interaction) in q_view.iter_mut() {
+        let pressed = interaction == Interaction::Pressed;
+        let overscroll = view.enable_overscroll.then_some(OverscrollConfig {
+            stiffness: view.overscroll_stiffness,
+            max_overscroll: view.max_overscroll,
+        });
+        let max_scroll = view.max_scroll;
+        let max_scroll_x = view.max_scroll_x;
+        let friction = view.friction;
+
         let mut iter = q_scroll.iter_many_mut(children);
         while let Some(mut scroll) = iter.fetch_next() {
-            if view.velocity.abs() > 16.0 {
-                let (value, velocity) = calc_velocity(scroll.pos_y, view.velocity, -view.friction, time.delta_seconds());
-                view.velocity = velocity;
-                scroll.pos_y = value;
-                scroll.pos_y = scroll.pos_y.clamp(view.max_scroll, 0.);
-            } else {
+            if view.direction.has_vertical() {
+                let (new_pos, new_velocity) = apply_scroll_axis(scroll.pos_y, view.velocity, max_scroll, 0.0, friction, pressed, overscroll, dt);
+                // only write through `Mut` when the value actually changed, so
+                // `Changed<ScrollableContent>`/`is_changed()` consumers (e.g. the scrollbar
+                // auto-hide timer) see a real signal instead of "changed" every frame.
+                if new_velocity != view.velocity {
+                    view.velocity = new_velocity;
+                }
+                if new_pos != scroll.pos_y {
+                    scroll.pos_y = new_pos;
+                }
+            } else if view.velocity != 0.0 {
                 view.velocity = 0.0;
             }
+
+            if view.direction.has_horizontal() {
+                let (new_pos, new_velocity) = apply_scroll_axis(scroll.pos_x, view.velocity_x, max_scroll_x, 0.0, friction, pressed, overscroll, dt);
+                if new_velocity != view.velocity_x {
+                    view.velocity_x = new_velocity;
+                }
+                if new_pos != scroll.pos_x {
+                    scroll.pos_x = new_pos;
+                }
+            } else if view.velocity_x != 0.0 {
+                view.velocity_x = 0.0;
+            }
         }
     }
 }
 
+#[derive(Clone, Copy)]
+struct OverscrollConfig {
+    stiffness: f32,
+    max_overscroll: f32,
+}
+
+/// Integrates one scroll axis for a frame: friction-based fling while in bounds, a
+/// critically-damped spring pulling an overscrolled, released axis back to its nearest
+/// bound, or (while `pressed`) a bounds-check extended by `max_overscroll`. Returns the new
+/// `(pos, velocity)`; pure so the caller can skip writing through change-detected storage
+/// when nothing actually moved.
+#[allow(clippy::too_many_arguments)]
+fn apply_scroll_axis(
+    pos: f32,
+    velocity: f32,
+    min: f32,
+    max: f32,
+    friction: f32,
+    pressed: bool,
+    overscroll: Option<OverscrollConfig>,
+    dt: f32,
+) -> (f32, f32) {
+    let over = if pos > max {
+        pos - max
+    } else if pos < min {
+        pos - min
+    } else {
+        0.0
+    };
+
+    if let Some(cfg) = overscroll {
+        if !pressed && over != 0.0 {
+            // critically damped spring: a = -k*x - 2*sqrt(k)*v
+            let damping = 2.0 * cfg.stiffness.sqrt();
+            let accel = -cfg.stiffness * over - damping * velocity;
+            let new_velocity = velocity + accel * dt;
+            let new_pos = pos + new_velocity * dt;
+            return if over.abs() < 0.5 && new_velocity.abs() < 1.0 {
+                (new_pos.clamp(min, max), 0.0)
+            } else {
+                (new_pos, new_velocity)
+            };
+        }
+    }
+
+    let (pos, velocity) = if velocity.abs() > 16.0 {
+        calc_velocity(pos, velocity, -friction, dt)
+    } else {
+        (pos, 0.0)
+    };
+
+    let pos = match overscroll {
+        Some(cfg) => pos.clamp(min - cfg.max_overscroll, max + cfg.max_overscroll),
+        None => pos.clamp(min, max),
+    };
+    (pos, velocity)
+}
+
 fn calc_velocity(value: f32, velocity: f32, friction: f32, delta_t: f32) -> (f32, f32) {
     (
         value - velocity / friction + velocity / friction * (friction * delta_t).exp(),
         velocity * (delta_t * friction).exp(),
     )
 }
-